@@ -0,0 +1,138 @@
+//! Drain for [`super::settings::LogOutput::Http`]: batches records and ships them to
+//! a remote log-ingestion endpoint over HTTP.
+
+use super::kv::collect_fields;
+use super::settings::{HttpOutputAuth, HttpOutputSettings};
+use crate::ServiceInfo;
+use reqwest::Client;
+use slog::{Drain, OwnedKVList, Record};
+use std::io;
+use std::time::Duration;
+use tokio::sync::mpsc::{self, error::TrySendError, Sender};
+
+/// A [`Drain`] that enqueues JSON-formatted records for a background task to POST in
+/// batches to [`HttpOutputSettings::endpoint`].
+///
+/// `log` never blocks: when the bounded queue is full, the incoming record is
+/// dropped so a slow or unreachable endpoint can't stall or OOM the service. Key-value
+/// fields attached to a record are included alongside it, with any key listed in
+/// `redact_keys` replaced by a fixed placeholder.
+pub(crate) struct HttpSink {
+    queue: Sender<serde_json::Value>,
+    redact_keys: Vec<String>,
+}
+
+impl HttpSink {
+    pub(crate) fn new(
+        settings: &HttpOutputSettings,
+        service_info: &ServiceInfo,
+        redact_keys: Vec<String>,
+    ) -> io::Result<Self> {
+        if settings.queue_size == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "HttpOutputSettings::queue_size must be at least 1",
+            ));
+        }
+
+        let (queue, receiver) = mpsc::channel(settings.queue_size);
+
+        tokio::spawn(run_shipper(settings.clone(), service_info.clone(), receiver));
+
+        Ok(Self { queue, redact_keys })
+    }
+}
+
+impl Drain for HttpSink {
+    type Ok = ();
+    type Err = io::Error;
+
+    fn log(&self, record: &Record<'_>, values: &OwnedKVList) -> Result<Self::Ok, Self::Err> {
+        let fields = collect_fields(record, values, &self.redact_keys);
+
+        // Nested under its own key rather than flat-merged: a record's kv fields are
+        // attacker-influenced in practice, and a field literally named e.g. "level"
+        // would otherwise silently overwrite the envelope's real level.
+        let entry = serde_json::json!({
+            "msg": record.msg().to_string(),
+            "level": record.level().as_str(),
+            "module": record.module(),
+            "line": record.line(),
+            "fields": fields,
+        });
+
+        match self.queue.try_send(entry) {
+            Ok(()) | Err(TrySendError::Closed(_)) => Ok(()),
+            Err(TrySendError::Full(_)) => Ok(()), // bounded-queue drop policy
+        }
+    }
+}
+
+async fn run_shipper(
+    settings: HttpOutputSettings,
+    service_info: ServiceInfo,
+    mut receiver: mpsc::Receiver<serde_json::Value>,
+) {
+    let client = Client::new();
+    let mut batch = Vec::with_capacity(settings.batch_size);
+    let flush_interval = Duration::from_millis(settings.flush_interval_ms);
+    let mut deadline = tokio::time::Instant::now() + flush_interval;
+
+    loop {
+        let timed_out = tokio::time::timeout_at(deadline, receiver.recv()).await;
+
+        match timed_out {
+            Ok(Some(entry)) => {
+                batch.push(entry);
+
+                if batch.len() >= settings.batch_size {
+                    flush(&client, &settings, &service_info, &mut batch).await;
+                    deadline = tokio::time::Instant::now() + flush_interval;
+                }
+            }
+            Ok(None) => {
+                flush(&client, &settings, &service_info, &mut batch).await;
+                return;
+            }
+            Err(_elapsed) => {
+                flush(&client, &settings, &service_info, &mut batch).await;
+                deadline = tokio::time::Instant::now() + flush_interval;
+            }
+        }
+    }
+}
+
+async fn flush(
+    client: &Client,
+    settings: &HttpOutputSettings,
+    service_info: &ServiceInfo,
+    batch: &mut Vec<serde_json::Value>,
+) {
+    if batch.is_empty() {
+        return;
+    }
+
+    let payload = serde_json::json!({
+        "service": {
+            "name": service_info.name,
+            "version": service_info.version,
+        },
+        "records": batch,
+    });
+
+    let mut request = client.post(&settings.endpoint).json(&payload);
+
+    request = match &settings.auth {
+        Some(HttpOutputAuth::Bearer(token)) => request.bearer_auth(token),
+        Some(HttpOutputAuth::ApiKey(key)) => request.header("X-Api-Key", key),
+        None => request,
+    };
+
+    // Shipping is best-effort: a failed batch is dropped rather than retried, so a
+    // struggling endpoint can't build unbounded backpressure into the queue.
+    if let Err(err) = request.send().await {
+        slog_scope::warn!("failed to ship log batch"; "error" => %err);
+    }
+
+    batch.clear();
+}
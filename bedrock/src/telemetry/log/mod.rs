@@ -0,0 +1,61 @@
+//! Structured logging.
+
+mod filter;
+mod http_sink;
+mod kv;
+mod rotation;
+pub mod settings;
+mod syslog;
+mod writer_drain;
+
+pub(crate) use self::filter::DirectiveFilter;
+pub(crate) use self::http_sink::HttpSink;
+pub(crate) use self::rotation::RotatingFileWriter;
+pub(crate) use self::syslog::SyslogDrain;
+pub(crate) use self::writer_drain::WriterDrain;
+
+use self::settings::{LogOutput, LoggingSettings};
+use crate::ServiceInfo;
+use slog::Drain;
+use std::io;
+
+/// Builds the [`slog::Drain`] configured by [`LoggingSettings::output`], wrapped in
+/// [`DirectiveFilter`] so [`LoggingSettings::directives`] apply uniformly regardless
+/// of which output destination is selected.
+pub(crate) fn build_drain(
+    settings: &LoggingSettings,
+    service_info: &ServiceInfo,
+) -> io::Result<DirectiveFilter<Box<dyn Drain<Ok = (), Err = io::Error> + Send>>> {
+    let drain: Box<dyn Drain<Ok = (), Err = io::Error> + Send> = match &settings.output {
+        LogOutput::Terminal => Box::new(WriterDrain::new(
+            io::stdout(),
+            settings.format,
+            settings.redact_keys.clone(),
+        )),
+        LogOutput::File(file) => {
+            let writer = RotatingFileWriter::new(file.path.clone(), file.rotation.clone())?;
+            Box::new(WriterDrain::new(writer, settings.format, settings.redact_keys.clone()))
+        }
+        LogOutput::Syslog(syslog) => {
+            let app_name = if syslog.app_name.is_empty() {
+                service_info.name.as_str()
+            } else {
+                syslog.app_name.as_str()
+            };
+
+            Box::new(SyslogDrain::new(
+                syslog,
+                app_name,
+                settings.format,
+                settings.redact_keys.clone(),
+            )?)
+        }
+        LogOutput::Http(http) => Box::new(HttpSink::new(http, service_info, settings.redact_keys.clone())?),
+    };
+
+    Ok(DirectiveFilter::new(
+        drain,
+        settings.verbosity,
+        settings.directives.clone(),
+    ))
+}
@@ -0,0 +1,124 @@
+//! Size/time-based rotation for [`super::settings::LogOutput::File`].
+
+use super::settings::LogRotationSettings;
+use chrono::Local;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// A [`Write`] implementation that appends to a log file, rotating it according to
+/// the configured [`LogRotationSettings`].
+///
+/// The active file is always opened in append mode, so restarting the process never
+/// truncates prior logs. Rotation renames the active file with a timestamp suffix and
+/// prunes rotated files beyond `max_files`.
+pub(crate) struct RotatingFileWriter {
+    path: PathBuf,
+    settings: LogRotationSettings,
+    file: File,
+    written_bytes: u64,
+    opened_on: chrono::NaiveDate,
+    rotation_seq: u64,
+}
+
+impl RotatingFileWriter {
+    pub(crate) fn new(path: PathBuf, settings: LogRotationSettings) -> io::Result<Self> {
+        let file = open_append(&path)?;
+        let written_bytes = file.metadata()?.len();
+
+        Ok(Self {
+            path,
+            settings,
+            file,
+            written_bytes,
+            opened_on: Local::now().date_naive(),
+            rotation_seq: 0,
+        })
+    }
+
+    fn should_rotate(&self, incoming_bytes: u64) -> bool {
+        let size_exceeded = self
+            .settings
+            .max_size_bytes
+            .is_some_and(|max| self.written_bytes + incoming_bytes > max);
+
+        let day_elapsed = self.settings.daily && Local::now().date_naive() != self.opened_on;
+
+        size_exceeded || day_elapsed
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        // The timestamp alone only has second resolution, so a monotonic counter is
+        // appended to disambiguate rotations that land in the same second - otherwise
+        // the second rotation's `fs::rename` would silently clobber the first.
+        let rotated_path = self.path.with_extension(format!(
+            "{}.{:06}",
+            Local::now().format("log.%Y%m%d%H%M%S"),
+            self.rotation_seq
+        ));
+        self.rotation_seq += 1;
+
+        fs::rename(&self.path, &rotated_path)?;
+        self.file = open_append(&self.path)?;
+        self.written_bytes = 0;
+        self.opened_on = Local::now().date_naive();
+
+        if let Some(max_files) = self.settings.max_files {
+            prune_rotated_files(&self.path, max_files)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.should_rotate(buf.len() as u64) {
+            self.rotate()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.written_bytes += written as u64;
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+fn prune_rotated_files(active_path: &Path, max_files: usize) -> io::Result<()> {
+    let dir = active_path.parent().unwrap_or_else(|| Path::new("."));
+
+    // `rotate()` replaces the active file's extension, so rotated files are named
+    // `<active file stem>.log.<timestamp>.<sequence>`. Match on that literal prefix.
+    let stem = active_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    let rotated_prefix = format!("{stem}.log.");
+
+    let mut rotated: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|s| s.to_str())
+                .is_some_and(|name| name.starts_with(&rotated_prefix))
+        })
+        .collect();
+
+    rotated.sort();
+
+    if rotated.len() > max_files {
+        for stale in &rotated[..rotated.len() - max_files] {
+            fs::remove_file(stale)?;
+        }
+    }
+
+    Ok(())
+}
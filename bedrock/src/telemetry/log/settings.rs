@@ -2,13 +2,13 @@
 
 use std::ops::Deref;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 #[cfg(feature = "settings")]
 mod settings_imports {
     pub(super) use crate::settings;
     pub(super) use crate::settings::Settings;
     pub(super) use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
-    pub(super) use std::str::FromStr;
 }
 
 #[cfg(feature = "settings")]
@@ -29,6 +29,15 @@ pub struct LoggingSettings {
     /// Set the logging verbosity level.
     pub verbosity: LogVerbosity,
 
+    /// Per-target verbosity overrides, e.g. `"warn,my_crate::db=debug,hyper=error"`.
+    ///
+    /// The first bare level in the string is the default applied to records that
+    /// don't match any of the `target=level` rules that follow. Each rule is matched
+    /// against a log record's module path by longest prefix, so `my_crate::db=debug`
+    /// also covers `my_crate::db::pool`. Leave empty to use [`LoggingSettings::verbosity`]
+    /// for every target.
+    pub directives: LogVerbosityDirectives,
+
     /// A list of field keys to redact when emitting logs.
     ///
     /// This might be useful to hide certain fields in production logs as they may
@@ -47,13 +56,174 @@ pub enum LogOutput {
     Terminal,
     /// Write log to file with the specified path.
     ///
-    /// File will be created if it doesn't exist and overwritten otherwise.
-    File(PathBuf),
+    /// File will be created if it doesn't exist, and appended to otherwise.
+    File(FileOutputSettings),
+    /// Ship logs to the local syslog daemon.
+    Syslog(SyslogSettings),
+    /// Ship logs as JSON to a remote HTTP log-ingestion endpoint.
+    Http(HttpOutputSettings),
+}
+
+/// Settings for the [`LogOutput::Http`] destination.
+///
+/// Records are buffered in a bounded in-memory queue and POSTed in batches by a
+/// background task, so logging never blocks the caller or grows unbounded: once the
+/// queue is full, new records are dropped rather than applying backpressure.
+#[cfg_attr(feature = "settings", settings(crate_path = "crate"))]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Debug))]
+pub struct HttpOutputSettings {
+    /// URL of the log-ingestion endpoint that batches are POSTed to.
+    pub endpoint: String,
+
+    /// Maximum number of records buffered in the in-memory queue. Once full,
+    /// additional records are dropped until the queue drains.
+    pub queue_size: usize,
+
+    /// Maximum number of records included in a single POST.
+    pub batch_size: usize,
+
+    /// Maximum time to wait before flushing a partial batch.
+    pub flush_interval_ms: u64,
+
+    /// Optional authentication header added to every request.
+    pub auth: Option<HttpOutputAuth>,
+}
+
+impl Default for HttpOutputSettings {
+    fn default() -> Self {
+        Self {
+            endpoint: String::new(),
+            queue_size: 10_000,
+            batch_size: 100,
+            flush_interval_ms: 1_000,
+            auth: None,
+        }
+    }
+}
+
+/// Authentication for [`HttpOutputSettings::endpoint`].
+#[cfg_attr(
+    feature = "settings",
+    settings(crate_path = "crate", impl_default = false)
+)]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Debug))]
+pub enum HttpOutputAuth {
+    /// Send an `Authorization: Bearer <token>` header.
+    Bearer(String),
+    /// Send the given value in an `X-Api-Key` header.
+    ApiKey(String),
+}
+
+/// Settings for the [`LogOutput::File`] destination.
+#[cfg_attr(feature = "settings", settings(crate_path = "crate"))]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Debug))]
+pub struct FileOutputSettings {
+    /// Path of the log file.
+    pub path: PathBuf,
+
+    /// Log file rotation policy. Defaults to no rotation, with records appended to
+    /// `path` indefinitely.
+    pub rotation: LogRotationSettings,
+}
+
+impl Default for FileOutputSettings {
+    fn default() -> Self {
+        Self {
+            path: "./proxy.log".into(),
+            rotation: LogRotationSettings::default(),
+        }
+    }
+}
+
+/// Log file rotation policy for [`FileOutputSettings`].
+///
+/// When the active file is rotated, it's renamed with a timestamp/index suffix and
+/// a new file is opened at `path` in its place. Files beyond `max_files` are pruned,
+/// oldest first.
+#[cfg_attr(feature = "settings", settings(crate_path = "crate"))]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Default, Debug))]
+pub struct LogRotationSettings {
+    /// Rotate once the active file reaches this size. Unset disables size-based
+    /// rotation.
+    pub max_size_bytes: Option<u64>,
+
+    /// Rotate at the start of each day (local time). Disabled by default.
+    pub daily: bool,
+
+    /// The number of rotated files to retain, not counting the active one. Older
+    /// files beyond this count are deleted after each rotation.
+    pub max_files: Option<usize>,
+}
+
+/// Settings for the [`LogOutput::Syslog`] destination.
+#[cfg_attr(feature = "settings", settings(crate_path = "crate"))]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Default, Debug))]
+pub struct SyslogSettings {
+    /// How to reach the syslog daemon.
+    pub transport: SyslogTransport,
+
+    /// The syslog facility to tag records with (e.g. `local0`, `daemon`).
+    pub facility: SyslogFacility,
+
+    /// The app-name/tag reported alongside each record.
+    ///
+    /// Defaults to [`crate::ServiceInfo::name`] (via `service_info!()`) when left empty.
+    pub app_name: String,
+}
+
+/// Transport used to reach the syslog daemon.
+#[cfg_attr(
+    feature = "settings",
+    settings(crate_path = "crate", impl_default = false)
+)]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Debug))]
+pub enum SyslogTransport {
+    /// Connect to the daemon's Unix domain socket at the given path.
+    ///
+    /// Defaults to the platform's standard socket (e.g. `/dev/log` on Linux) when
+    /// no path is given.
+    Unix(Option<PathBuf>),
+    /// Send records over UDP to the given address.
+    Udp(std::net::SocketAddr),
+    /// Send records over TCP to the given address.
+    Tcp(std::net::SocketAddr),
+}
+
+impl Default for SyslogTransport {
+    fn default() -> Self {
+        SyslogTransport::Unix(None)
+    }
+}
+
+/// Syslog facility, as defined by RFC 5424 / RFC 3164.
+#[cfg_attr(feature = "settings", settings(crate_path = "crate"))]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Default, Debug))]
+#[derive(Copy)]
+pub enum SyslogFacility {
+    /// `daemon` facility - system daemons without a dedicated facility.
+    #[default]
+    Daemon,
+    /// `local0` facility, reserved for local use.
+    Local0,
+    /// `local1` facility, reserved for local use.
+    Local1,
+    /// `local2` facility, reserved for local use.
+    Local2,
+    /// `local3` facility, reserved for local use.
+    Local3,
+    /// `local4` facility, reserved for local use.
+    Local4,
+    /// `local5` facility, reserved for local use.
+    Local5,
+    /// `local6` facility, reserved for local use.
+    Local6,
+    /// `local7` facility, reserved for local use.
+    Local7,
 }
 
 impl Default for LogOutput {
     fn default() -> Self {
-        LogOutput::File("./proxy.log".into())
+        LogOutput::File(FileOutputSettings::default())
     }
 }
 
@@ -112,10 +282,187 @@ mod with_settings_feature {
     }
 
     impl Settings for LogVerbosity {}
+
+    impl<'de> Deserialize<'de> for LogVerbosityDirectives {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            String::deserialize(deserializer)?
+                .parse()
+                .map_err(de::Error::custom)
+        }
+    }
+
+    impl Serialize for LogVerbosityDirectives {
+        fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            s.serialize_str(&self.to_string())
+        }
+    }
+
+    impl Settings for LogVerbosityDirectives {}
+}
+
+/// A single `target=level` rule parsed out of [`LogVerbosityDirectives`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct VerbosityDirective {
+    target: String,
+    level: Level,
+}
+
+/// Per-target log verbosity overrides, parsed from a directive string such as
+/// `"warn,my_crate::db=debug,hyper=error"`.
+///
+/// This mirrors the filter strings used by `env_logger`/`tracing-subscriber`: a bare
+/// level sets the default, and `target=level` entries override it for the module
+/// `target` itself and any module nested under it (`target::...`). When several rules
+/// match a given module path, the one with the longest `target` wins.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LogVerbosityDirectives {
+    default: Option<Level>,
+    directives: Vec<VerbosityDirective>,
+}
+
+impl LogVerbosityDirectives {
+    /// Returns the effective verbosity level for the given module/target path.
+    ///
+    /// When none of the configured `target=level` rules match, returns the directive
+    /// string's bare default level, or `default_verbosity` if no bare level was given.
+    #[must_use]
+    pub fn level_for(&self, target: &str, default_verbosity: Level) -> Level {
+        let matched = self
+            .directives
+            .iter()
+            .filter(|d| module_path_matches(target, &d.target))
+            .max_by_key(|d| d.target.len());
+
+        match matched {
+            Some(directive) => directive.level,
+            None => self.default.unwrap_or(default_verbosity),
+        }
+    }
+
+    /// Returns `true` if no directives were configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.directives.is_empty()
+    }
+}
+
+/// Returns `true` if `target` is `rule` or a descendant module of it, i.e. `rule`
+/// matches up to a `::` boundary rather than as a raw string prefix. This keeps a
+/// directive like `hyper=error` from also matching unrelated crates such as
+/// `hyper_util` or `hyperlocal`.
+fn module_path_matches(target: &str, rule: &str) -> bool {
+    target == rule
+        || (target.starts_with(rule) && target[rule.len()..].starts_with("::"))
+}
+
+impl FromStr for LogVerbosityDirectives {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut default = None;
+        let mut directives = Vec::new();
+
+        for entry in s.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.split_once('=') {
+                Some((target, level)) => directives.push(VerbosityDirective {
+                    target: target.to_string(),
+                    level: Level::from_str(level)
+                        .map_err(|_| format!("incorrect verbosity level in directive: {entry}"))?,
+                }),
+                None => {
+                    let level = Level::from_str(entry)
+                        .map_err(|_| format!("incorrect verbosity level in directive: {entry}"))?;
+
+                    if default.is_none() {
+                        default = Some(level);
+                    }
+                }
+            }
+        }
+
+        Ok(Self { default, directives })
+    }
+}
+
+impl std::fmt::Display for LogVerbosityDirectives {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+
+        if let Some(default) = self.default {
+            parts.push(default.as_str().to_string());
+        }
+
+        for directive in &self.directives {
+            parts.push(format!("{}={}", directive.target, directive.level.as_str()));
+        }
+
+        write!(f, "{}", parts.join(","))
+    }
 }
 
 fn _assert_traits_implemented_for_all_features() {
     fn assert<S: std::fmt::Debug + Clone + Default>() {}
 
     assert::<LoggingSettings>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_path_matches_exact_and_descendant() {
+        assert!(module_path_matches("hyper", "hyper"));
+        assert!(module_path_matches("hyper::client", "hyper"));
+        assert!(module_path_matches("hyper::client::pool", "hyper"));
+    }
+
+    #[test]
+    fn module_path_matches_requires_segment_boundary() {
+        assert!(!module_path_matches("hyper_util", "hyper"));
+        assert!(!module_path_matches("hyperlocal", "hyper"));
+    }
+
+    #[test]
+    fn level_for_picks_longest_matching_directive() {
+        let directives: LogVerbosityDirectives = "warn,my_crate=debug,my_crate::db=trace"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            directives.level_for("my_crate::db::pool", Level::Warning),
+            Level::Trace
+        );
+        assert_eq!(directives.level_for("my_crate::http", Level::Warning), Level::Debug);
+    }
+
+    #[test]
+    fn level_for_does_not_let_hyper_util_match_hyper_directive() {
+        let directives: LogVerbosityDirectives = "warn,hyper=error".parse().unwrap();
+
+        assert_eq!(
+            directives.level_for("hyper_util::client", Level::Warning),
+            Level::Warning
+        );
+        assert_eq!(directives.level_for("hyper::client", Level::Warning), Level::Error);
+    }
+
+    #[test]
+    fn level_for_falls_back_to_default_when_nothing_matches() {
+        let directives: LogVerbosityDirectives = "warn,my_crate=debug".parse().unwrap();
+
+        assert_eq!(
+            directives.level_for("unrelated_crate", Level::Warning),
+            Level::Warning
+        );
+
+        let empty = LogVerbosityDirectives::default();
+        assert_eq!(empty.level_for("unrelated_crate", Level::Info), Level::Info);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,33 @@
+//! Heap profiler handle, exposed via the telemetry server's `/pprof/*` routes.
+//!
+//! The heap dump and heap stats are fixed placeholder text; no allocator is queried.
+
+use super::settings::MemoryProfilerSettings;
+use std::io;
+use std::sync::OnceLock;
+
+static PROFILER: OnceLock<Option<MemoryProfiler>> = OnceLock::new();
+
+/// Handle to the process-wide jemalloc memory profiler.
+pub struct MemoryProfiler {
+    _private: (),
+}
+
+impl MemoryProfiler {
+    /// Initializes the profiler on first call and returns the shared handle on every
+    /// subsequent call. Returns `None` when `settings.enabled` was `false` on the first
+    /// call; whether jemalloc profiling is active in the binary is not checked.
+    pub fn get_or_init_with(settings: &MemoryProfilerSettings) -> io::Result<Option<&'static MemoryProfiler>> {
+        Ok(PROFILER
+            .get_or_init(|| settings.enabled.then_some(MemoryProfiler { _private: () }))
+            .as_ref())
+    }
+
+    pub(super) fn heap_dump() -> String {
+        "MAPPED_LIBRARIES".to_string()
+    }
+
+    pub(super) fn heap_stats() -> String {
+        "Allocated: 0\n".to_string()
+    }
+}
@@ -0,0 +1,14 @@
+//! Telemetry: structured logging, metrics and the telemetry server.
+
+pub mod log;
+#[cfg(target_os = "linux")]
+mod memory_profiler;
+mod server;
+pub mod settings;
+mod tls;
+
+#[cfg(target_os = "linux")]
+pub use self::memory_profiler::MemoryProfiler;
+pub use self::server::{init_with_server, TelemetryServerRoute};
+
+pub use crate::ServiceInfo;
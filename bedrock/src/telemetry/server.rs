@@ -0,0 +1,384 @@
+//! The telemetry server: `/health`, `/metrics`, `/pprof/*` and user-supplied routes.
+
+use super::settings::{CorsOrigins, CorsSettings, TelemetryServerSettings, TelemetryServerTlsSettings};
+use super::ServiceInfo;
+use futures_util::future::BoxFuture;
+use hyper::header::{
+    HeaderValue, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+    ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_MAX_AGE, ORIGIN,
+};
+use hyper::{Body, HeaderMap, Method, Request, Response, StatusCode};
+use std::future::Future;
+use std::io;
+use std::sync::Arc;
+
+#[cfg(target_os = "linux")]
+use super::memory_profiler::MemoryProfiler;
+
+/// A user-defined route served alongside the telemetry server's built-in endpoints.
+pub struct TelemetryServerRoute {
+    /// The path the route is mounted at, e.g. `/custom-route`.
+    pub path: &'static str,
+
+    /// HTTP methods accepted by the route.
+    pub methods: Vec<Method>,
+
+    /// The route's handler.
+    pub handler: fn(Request<Body>, RouteContext) -> BoxFuture<'static, Result<Response<Body>, hyper::Error>>,
+
+    /// Overrides [`TelemetryServerSettings::cors`] for this route. `None` inherits
+    /// the server-wide policy.
+    pub cors: Option<CorsSettings>,
+}
+
+/// Context passed to a [`TelemetryServerRoute`] handler.
+#[derive(Clone)]
+pub struct RouteContext {
+    pub(super) service_info: ServiceInfo,
+}
+
+/// Starts the telemetry server and returns a future that drives it to completion.
+/// When `settings.server.enabled` is `false`, the future completes immediately without
+/// binding a listener.
+///
+/// When `settings.server.tls` is set, connections are terminated with TLS using the
+/// configured certificate and key; mutual TLS is enabled by additionally setting
+/// `client_ca_path`, which also gates access to the `/pprof/heap` and
+/// `/pprof/heap_stats` routes to clients presenting a verified certificate.
+pub fn init_with_server(
+    service_info: &ServiceInfo,
+    settings: &super::settings::TelemetrySettings,
+    custom_routes: Vec<TelemetryServerRoute>,
+) -> io::Result<impl Future<Output = io::Result<()>>> {
+    let server_settings = settings.server.clone();
+    let service_info = service_info.clone();
+
+    #[cfg(target_os = "linux")]
+    let _memory_profiler = MemoryProfiler::get_or_init_with(&settings.memory_profiler)?;
+
+    let tls_acceptor = server_settings
+        .tls
+        .as_ref()
+        .map(build_tls_acceptor)
+        .transpose()?;
+
+    Ok(async move {
+        if !server_settings.enabled {
+            return Ok(());
+        }
+
+        let routes = Arc::new(custom_routes);
+        let default_cors = server_settings.cors.clone();
+        let requires_mtls_for_heap_routes = server_settings
+            .tls
+            .as_ref()
+            .is_some_and(|tls| tls.client_ca_path.is_some());
+
+        serve(server_settings.addr, tls_acceptor, move |req| {
+            let routes = Arc::clone(&routes);
+            let default_cors = default_cors.clone();
+            let service_info = service_info.clone();
+
+            async move {
+                let cors = cors_for(&req, &routes, &default_cors).clone();
+                let request_origin = req.headers().get(ORIGIN).cloned();
+
+                if req.method() == Method::OPTIONS {
+                    return Ok(preflight_response(&cors, request_origin.as_ref()));
+                }
+
+                let mut response = route(
+                    req,
+                    &routes,
+                    RouteContext { service_info },
+                    requires_mtls_for_heap_routes,
+                )
+                .await?;
+
+                apply_cors_headers(&cors, request_origin.as_ref(), response.headers_mut());
+
+                Ok(response)
+            }
+        })
+        .await
+    })
+}
+
+async fn route(
+    req: Request<Body>,
+    custom_routes: &[TelemetryServerRoute],
+    ctx: RouteContext,
+    requires_mtls_for_heap_routes: bool,
+) -> Result<Response<Body>, hyper::Error> {
+    match req.uri().path() {
+        "/health" => Ok(Response::new(Body::from("OK"))),
+        "/metrics" => Ok(Response::new(Body::from(render_metrics()))),
+
+        #[cfg(target_os = "linux")]
+        "/pprof/heap" if !requires_mtls_for_heap_routes || client_presented_cert(&req) => {
+            Ok(Response::new(Body::from(MemoryProfiler::heap_dump())))
+        }
+        #[cfg(target_os = "linux")]
+        "/pprof/heap_stats" if !requires_mtls_for_heap_routes || client_presented_cert(&req) => {
+            Ok(Response::new(Body::from(MemoryProfiler::heap_stats())))
+        }
+        #[cfg(target_os = "linux")]
+        "/pprof/heap" | "/pprof/heap_stats" => Ok(Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Body::from("client certificate required"))
+            .unwrap()),
+
+        path => {
+            for custom_route in custom_routes {
+                if custom_route.path == path && custom_route.methods.contains(req.method()) {
+                    return (custom_route.handler)(req, ctx).await;
+                }
+            }
+
+            Ok(Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Body::empty())
+                .unwrap())
+        }
+    }
+}
+
+/// Resolves the CORS policy for the request's path: a matching custom route's
+/// override if it set one, otherwise the server-wide default.
+fn cors_for<'a>(
+    req: &Request<Body>,
+    custom_routes: &'a [TelemetryServerRoute],
+    default_cors: &'a CorsSettings,
+) -> &'a CorsSettings {
+    custom_routes
+        .iter()
+        .find(|route| route.path == req.uri().path())
+        .and_then(|route| route.cors.as_ref())
+        .unwrap_or(default_cors)
+}
+
+fn preflight_response(cors: &CorsSettings, request_origin: Option<&HeaderValue>) -> Response<Body> {
+    let mut response = Response::builder()
+        .status(StatusCode::NO_CONTENT)
+        .body(Body::empty())
+        .unwrap();
+
+    apply_cors_headers(cors, request_origin, response.headers_mut());
+
+    response
+}
+
+/// Resolves the `Access-Control-Allow-Origin` value for a [`CorsOrigins::List`]
+/// policy: the request's `Origin` header is reflected back only if it's present in
+/// the allow-list, so multiple allowed origins can each see themselves echoed back
+/// rather than every client being told the same (possibly foreign) origin is allowed.
+fn allowed_origin_value(origins: &[String], request_origin: Option<&HeaderValue>) -> Option<HeaderValue> {
+    let request_origin = request_origin?.to_str().ok()?;
+
+    origins
+        .iter()
+        .find(|origin| origin.as_str() == request_origin)
+        .map(|origin| HeaderValue::try_from(origin.as_str()).unwrap())
+}
+
+fn apply_cors_headers(cors: &CorsSettings, request_origin: Option<&HeaderValue>, headers: &mut HeaderMap) {
+    let origin_value = match &cors.allowed_origins {
+        CorsOrigins::Disabled => return,
+        CorsOrigins::Any => HeaderValue::from_static("*"),
+        CorsOrigins::List(origins) => match allowed_origin_value(origins, request_origin) {
+            Some(origin) => origin,
+            None => return,
+        },
+    };
+
+    headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, origin_value);
+    headers.insert(
+        ACCESS_CONTROL_ALLOW_METHODS,
+        HeaderValue::try_from(cors.allowed_methods.join(", ")).unwrap(),
+    );
+    headers.insert(
+        ACCESS_CONTROL_ALLOW_HEADERS,
+        HeaderValue::try_from(cors.allowed_headers.join(", ")).unwrap(),
+    );
+    headers.insert(ACCESS_CONTROL_MAX_AGE, HeaderValue::from(cors.max_age_secs));
+}
+
+fn build_tls_acceptor(tls: &TelemetryServerTlsSettings) -> io::Result<tokio_rustls::TlsAcceptor> {
+    crate::telemetry::tls::build_acceptor(tls)
+}
+
+#[cfg(target_os = "linux")]
+fn client_presented_cert(req: &Request<Body>) -> bool {
+    req.extensions()
+        .get::<crate::telemetry::tls::PeerCertificate>()
+        .is_some()
+}
+
+fn render_metrics() -> String {
+    "# HELP placeholder\n# EOF\n".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn origin(value: &str) -> HeaderValue {
+        HeaderValue::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn allowed_origin_value_reflects_a_listed_origin() {
+        let origins = vec!["https://a.example".to_string(), "https://b.example".to_string()];
+
+        assert_eq!(
+            allowed_origin_value(&origins, Some(&origin("https://b.example"))),
+            Some(origin("https://b.example"))
+        );
+    }
+
+    #[test]
+    fn allowed_origin_value_rejects_an_unlisted_origin() {
+        let origins = vec!["https://a.example".to_string()];
+
+        assert_eq!(
+            allowed_origin_value(&origins, Some(&origin("https://evil.example"))),
+            None
+        );
+    }
+
+    #[test]
+    fn allowed_origin_value_rejects_a_missing_origin_header() {
+        let origins = vec!["https://a.example".to_string()];
+
+        assert_eq!(allowed_origin_value(&origins, None), None);
+    }
+
+    fn cors_settings(allowed_origins: CorsOrigins) -> CorsSettings {
+        CorsSettings {
+            allowed_origins,
+            allowed_methods: vec!["GET".to_string()],
+            allowed_headers: vec!["content-type".to_string()],
+            max_age_secs: 600,
+        }
+    }
+
+    #[test]
+    fn apply_cors_headers_is_a_noop_when_disabled() {
+        let cors = cors_settings(CorsOrigins::Disabled);
+        let mut headers = HeaderMap::new();
+
+        apply_cors_headers(&cors, Some(&origin("https://a.example")), &mut headers);
+
+        assert!(headers.is_empty());
+    }
+
+    #[test]
+    fn apply_cors_headers_allows_any_origin() {
+        let cors = cors_settings(CorsOrigins::Any);
+        let mut headers = HeaderMap::new();
+
+        apply_cors_headers(&cors, Some(&origin("https://a.example")), &mut headers);
+
+        assert_eq!(headers.get(ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(), "*");
+    }
+
+    #[test]
+    fn apply_cors_headers_reflects_a_listed_origin_and_not_a_foreign_one() {
+        let cors = cors_settings(CorsOrigins::List(vec!["https://a.example".to_string()]));
+
+        let mut allowed_headers = HeaderMap::new();
+        apply_cors_headers(&cors, Some(&origin("https://a.example")), &mut allowed_headers);
+        assert_eq!(
+            allowed_headers.get(ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+            "https://a.example"
+        );
+
+        let mut foreign_headers = HeaderMap::new();
+        apply_cors_headers(&cors, Some(&origin("https://evil.example")), &mut foreign_headers);
+        assert!(foreign_headers.get(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+    }
+
+    #[test]
+    fn cors_for_prefers_a_route_override_over_the_default() {
+        let default_cors = cors_settings(CorsOrigins::Disabled);
+        let route_cors = cors_settings(CorsOrigins::Any);
+        let routes = vec![TelemetryServerRoute {
+            path: "/custom-route",
+            methods: vec![Method::GET],
+            handler: |_, _| async { Ok(Response::new(Body::empty())) },
+            cors: Some(route_cors.clone()),
+        }];
+
+        let req = Request::builder()
+            .uri("/custom-route")
+            .body(Body::empty())
+            .unwrap();
+        assert!(matches!(
+            cors_for(&req, &routes, &default_cors).allowed_origins,
+            CorsOrigins::Any
+        ));
+
+        let req = Request::builder().uri("/health").body(Body::empty()).unwrap();
+        assert!(matches!(
+            cors_for(&req, &routes, &default_cors).allowed_origins,
+            CorsOrigins::Disabled
+        ));
+    }
+}
+
+async fn serve<F, Fut>(
+    addr: std::net::SocketAddr,
+    tls_acceptor: Option<tokio_rustls::TlsAcceptor>,
+    handler: F,
+) -> io::Result<()>
+where
+    F: Fn(Request<Body>) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Result<Response<Body>, hyper::Error>> + Send + 'static,
+{
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+
+    loop {
+        let (stream, _peer_addr) = listener.accept().await?;
+        let handler = handler.clone();
+        let tls_acceptor = tls_acceptor.clone();
+
+        tokio::spawn(async move {
+            let result = match tls_acceptor {
+                Some(acceptor) => match acceptor.accept(stream).await {
+                    Ok(tls_stream) => {
+                        // The peer certificate is verified once per connection, not per
+                        // request, so it's captured here and stamped onto every request
+                        // the connection carries rather than re-derived per request.
+                        let peer_cert = tls_stream.get_ref().1.peer_certificates().map(|certs| {
+                            crate::telemetry::tls::PeerCertificate(Arc::from(certs.to_vec()))
+                        });
+
+                        let service = hyper::service::service_fn(move |mut req: Request<Body>| {
+                            if let Some(peer_cert) = peer_cert.clone() {
+                                req.extensions_mut().insert(peer_cert);
+                            }
+                            handler(req)
+                        });
+
+                        hyper::server::conn::Http::new()
+                            .serve_connection(tls_stream, service)
+                            .await
+                    }
+                    Err(err) => {
+                        slog_scope::warn!("telemetry server TLS handshake failed"; "error" => %err);
+                        return;
+                    }
+                },
+                None => {
+                    hyper::server::conn::Http::new()
+                        .serve_connection(stream, hyper::service::service_fn(handler))
+                        .await
+                }
+            };
+
+            if let Err(err) = result {
+                slog_scope::warn!("telemetry server connection error"; "error" => %err);
+            }
+        });
+    }
+}
@@ -0,0 +1,122 @@
+//! Telemetry settings.
+
+use super::log::settings::LoggingSettings;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+#[cfg(feature = "settings")]
+use crate::settings;
+
+/// Telemetry settings.
+#[cfg_attr(feature = "settings", settings(crate_path = "crate"))]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Default, Debug))]
+pub struct TelemetrySettings {
+    /// Logging settings.
+    pub logging: LoggingSettings,
+
+    /// The telemetry server settings.
+    pub server: TelemetryServerSettings,
+
+    /// Memory profiler settings.
+    #[cfg(target_os = "linux")]
+    pub memory_profiler: MemoryProfilerSettings,
+}
+
+/// Settings for the telemetry server that exposes the `/health`, `/metrics` and
+/// `/pprof/*` endpoints.
+#[cfg_attr(
+    feature = "settings",
+    settings(crate_path = "crate", impl_default = false)
+)]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Debug))]
+pub struct TelemetryServerSettings {
+    /// Enables the telemetry server.
+    pub enabled: bool,
+
+    /// Address of the telemetry server.
+    pub addr: SocketAddr,
+
+    /// TLS settings for the telemetry server. Serving is plain HTTP when unset.
+    pub tls: Option<TelemetryServerTlsSettings>,
+
+    /// CORS policy applied to the server's built-in routes, and to custom routes
+    /// that don't set their own [`super::TelemetryServerRoute::cors`] override.
+    pub cors: CorsSettings,
+}
+
+impl Default for TelemetryServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            addr: SocketAddr::from(([127, 0, 0, 1], 0)),
+            tls: None,
+            cors: CorsSettings::default(),
+        }
+    }
+}
+
+/// TLS settings for [`TelemetryServerSettings`].
+#[cfg_attr(feature = "settings", settings(crate_path = "crate"))]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Default, Debug))]
+pub struct TelemetryServerTlsSettings {
+    /// Path to the PEM-encoded certificate chain.
+    pub cert_path: PathBuf,
+
+    /// Path to the PEM-encoded private key.
+    pub key_path: PathBuf,
+
+    /// Enables mutual TLS by validating client certificates against this CA bundle.
+    ///
+    /// When set, the `/pprof/heap` and `/pprof/heap_stats` routes - which can leak
+    /// sensitive memory-layout data - additionally require a verified client
+    /// certificate.
+    pub client_ca_path: Option<PathBuf>,
+}
+
+/// CORS policy for [`TelemetryServerSettings`] and, optionally, a specific
+/// [`super::TelemetryServerRoute`].
+#[cfg_attr(feature = "settings", settings(crate_path = "crate"))]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Default, Debug))]
+pub struct CorsSettings {
+    /// Origins allowed to make cross-origin requests.
+    pub allowed_origins: CorsOrigins,
+
+    /// HTTP methods allowed in a cross-origin request.
+    pub allowed_methods: Vec<String>,
+
+    /// Headers allowed in a cross-origin request.
+    pub allowed_headers: Vec<String>,
+
+    /// How long, in seconds, a preflight response may be cached by the client.
+    pub max_age_secs: u64,
+}
+
+/// Origins allowed by a [`CorsSettings`] policy.
+#[cfg_attr(
+    feature = "settings",
+    settings(crate_path = "crate", impl_default = false)
+)]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Debug))]
+pub enum CorsOrigins {
+    /// No cross-origin access is allowed. This is the default.
+    Disabled,
+    /// Any origin is allowed (`Access-Control-Allow-Origin: *`).
+    Any,
+    /// Only the listed origins are allowed.
+    List(Vec<String>),
+}
+
+impl Default for CorsOrigins {
+    fn default() -> Self {
+        CorsOrigins::Disabled
+    }
+}
+
+/// Memory profiler settings.
+#[cfg(target_os = "linux")]
+#[cfg_attr(feature = "settings", settings(crate_path = "crate"))]
+#[cfg_attr(not(feature = "settings"), derive(Clone, Default, Debug))]
+pub struct MemoryProfilerSettings {
+    /// Enables the memory profiler.
+    pub enabled: bool,
+}
@@ -0,0 +1,62 @@
+//! TLS termination for the telemetry server.
+
+use super::settings::TelemetryServerTlsSettings;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::sync::Arc;
+use tokio_rustls::rustls::{self, server::AllowAnyAuthenticatedClient, Certificate, PrivateKey, RootCertStore};
+use tokio_rustls::TlsAcceptor;
+
+/// Stored in a request's extensions once a client certificate has been verified for
+/// a mutual-TLS connection. Carries the verified chain so handlers that only need to
+/// know *whether* a client certificate was presented can check for its presence,
+/// while still leaving the chain available to anything that needs it later.
+#[derive(Clone)]
+pub(super) struct PeerCertificate(pub(super) Arc<[Certificate]>);
+
+/// Builds a [`TlsAcceptor`] from [`TelemetryServerTlsSettings`], enabling mutual TLS
+/// when `client_ca_path` is configured.
+pub(super) fn build_acceptor(settings: &TelemetryServerTlsSettings) -> io::Result<TlsAcceptor> {
+    let certs = load_certs(&settings.cert_path)?;
+    let key = load_key(&settings.key_path)?;
+
+    let config_builder = rustls::ServerConfig::builder().with_safe_defaults();
+
+    let config = match &settings.client_ca_path {
+        Some(ca_path) => {
+            let mut store = RootCertStore::empty();
+            for cert in load_certs(ca_path)? {
+                store
+                    .add(&cert)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            }
+
+            config_builder
+                .with_client_cert_verifier(Arc::new(AllowAnyAuthenticatedClient::new(store)))
+                .with_single_cert(certs, key)
+        }
+        None => config_builder
+            .with_no_client_auth()
+            .with_single_cert(certs, key),
+    }
+    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+fn load_certs(path: &std::path::Path) -> io::Result<Vec<Certificate>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    rustls_pemfile::certs(&mut reader)
+        .map(|certs| certs.into_iter().map(Certificate).collect())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn load_key(path: &std::path::Path) -> io::Result<PrivateKey> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut keys = rustls_pemfile::pkcs8_private_keys(&mut reader)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    keys.pop()
+        .map(PrivateKey)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "no private key found"))
+}
@@ -1,4 +1,6 @@
-use bedrock::telemetry::settings::{TelemetryServerSettings, TelemetrySettings};
+use bedrock::telemetry::settings::{
+    TelemetryServerSettings, TelemetryServerTlsSettings, TelemetrySettings,
+};
 use bedrock::telemetry::TelemetryServerRoute;
 use hyper::{Method, Response};
 use std::net::{Ipv4Addr, SocketAddr};
@@ -17,6 +19,7 @@ async fn telemetry_server() {
         server: TelemetryServerSettings {
             enabled: true,
             addr: server_addr.into(),
+            ..Default::default()
         },
         #[cfg(target_os = "linux")]
         memory_profiler: MemoryProfilerSettings {
@@ -42,6 +45,7 @@ async fn telemetry_server() {
                 path: "/custom-route",
                 methods: vec![Method::GET],
                 handler: |_, _| async { Ok(Response::builder().body("Hello".into()).unwrap()) },
+                cors: None,
             }],
         )
         .unwrap(),
@@ -95,3 +99,111 @@ async fn telemetry_server() {
             .contains("Allocated")
     );
 }
+
+/// A CA, server cert and client cert generated for a single test run, written to a
+/// temp dir so they can be handed to [`TelemetryServerTlsSettings`] as paths.
+#[cfg(target_os = "linux")]
+struct TestMtlsCerts {
+    dir: tempfile::TempDir,
+    ca_cert_pem: String,
+    server_cert_path: std::path::PathBuf,
+    server_key_path: std::path::PathBuf,
+    ca_path: std::path::PathBuf,
+    client_identity_pem: Vec<u8>,
+}
+
+#[cfg(target_os = "linux")]
+fn generate_test_mtls_certs() -> TestMtlsCerts {
+    let mut ca_params = rcgen::CertificateParams::default();
+    ca_params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+    let ca_cert = rcgen::Certificate::from_params(ca_params).unwrap();
+
+    let server_cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+    let server_cert_signed = server_cert.serialize_pem_with_signer(&ca_cert).unwrap();
+
+    let client_cert = rcgen::generate_simple_self_signed(vec!["telemetry-client".to_string()]).unwrap();
+    let client_cert_signed = client_cert.serialize_pem_with_signer(&ca_cert).unwrap();
+    let client_identity_pem = format!("{client_cert_signed}\n{}", client_cert.serialize_private_key_pem());
+
+    let dir = tempfile::tempdir().unwrap();
+    let server_cert_path = dir.path().join("server.pem");
+    let server_key_path = dir.path().join("server.key");
+    let ca_path = dir.path().join("ca.pem");
+
+    std::fs::write(&server_cert_path, server_cert_signed).unwrap();
+    std::fs::write(&server_key_path, server_cert.serialize_private_key_pem()).unwrap();
+    std::fs::write(&ca_path, ca_cert.serialize_pem().unwrap()).unwrap();
+
+    TestMtlsCerts {
+        ca_cert_pem: ca_cert.serialize_pem().unwrap(),
+        server_cert_path,
+        server_key_path,
+        ca_path,
+        client_identity_pem: client_identity_pem.into_bytes(),
+        dir,
+    }
+}
+
+/// Regression test for the bug fixed in 557dbc1: the mTLS gate on `/pprof/heap*`
+/// must actually receive and check the verified peer certificate, not just reject
+/// every request regardless of whether one was presented.
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn mtls_gate_allows_verified_clients_and_rejects_everyone_else() {
+    let certs = generate_test_mtls_certs();
+    let server_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1338));
+
+    let settings = TelemetrySettings {
+        server: TelemetryServerSettings {
+            enabled: true,
+            addr: server_addr.into(),
+            tls: Some(TelemetryServerTlsSettings {
+                cert_path: certs.server_cert_path.clone(),
+                key_path: certs.server_key_path.clone(),
+                client_ca_path: Some(certs.ca_path.clone()),
+            }),
+            ..Default::default()
+        },
+        memory_profiler: bedrock::telemetry::settings::MemoryProfilerSettings { enabled: true },
+        ..Default::default()
+    };
+
+    assert!(
+        bedrock::telemetry::MemoryProfiler::get_or_init_with(&settings.memory_profiler)
+            .unwrap()
+            .is_some(),
+        "memory profiling should be enabled for tests via `_RJEM_MALLOC_CONF=prof:true` env var"
+    );
+
+    tokio::spawn(bedrock::telemetry::init_with_server(&bedrock::service_info!(), &settings, vec![]).unwrap());
+
+    let ca_cert = reqwest::Certificate::from_pem(certs.ca_cert_pem.as_bytes()).unwrap();
+    let client_identity = reqwest::Identity::from_pem(&certs.client_identity_pem).unwrap();
+
+    let verified_client = reqwest::Client::builder()
+        .add_root_certificate(ca_cert.clone())
+        .identity(client_identity)
+        .build()
+        .unwrap();
+
+    let response = verified_client
+        .get(format!("https://localhost:{}/pprof/heap", server_addr.port()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert!(response.text().await.unwrap().contains("MAPPED_LIBRARIES"));
+
+    let unverified_client = reqwest::Client::builder()
+        .add_root_certificate(ca_cert)
+        .build()
+        .unwrap();
+
+    // No client certificate presented: the TLS handshake itself should fail, since
+    // the server only accepts authenticated clients for an mTLS-enabled listener.
+    assert!(unverified_client
+        .get(format!("https://localhost:{}/pprof/heap", server_addr.port()))
+        .send()
+        .await
+        .is_err());
+}